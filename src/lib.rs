@@ -2,24 +2,25 @@
 
 //! CharStream is a hacked bi-directional char iterator that takes ownership of a
 //! `String` and grants the client an ability to scan back and forth through this
-//! string. A CharStream is not a ring; an attempt to iterate past the front or end
-//! of the stream will fail with CharStreamError::FallsOffEnd
+//! string. By default a CharStream is not a ring; an attempt to iterate past the
+//! front or end of the stream will fail with CharStreamError::FallsOffEnd. A
+//! CharStream constructed with `CharStream::ring` instead wraps: stepping past
+//! the end resumes at the front and stepping before the front resumes at the end.
 //!
-//! CharStream is takes 2N in space where N is the number of characters in the
-//! original string. This is guaranteed as a Vector holding an internal cache of
-//! the String is allocated on construction. This not ideal.
+//! CharStream's backing store is a single `Vec<char>` of length N, collected
+//! once up front at construction, where N is the number of characters (not
+//! bytes) in the original string. This gives correct per-position characters
+//! for multibyte input and O(1) random access.
 //!
 //! This value structure is designed to allow the caller to hold an immutable
 //! instance to CharStream, since only the underlying implementation details of
 //! CharStream need to change. This is done using interior mutability.
+use std::borrow::Cow;
 use std::cell::RefCell;
 
 #[derive(Debug, PartialEq)]
 pub enum CharStreamError {
     /// An attempt was made to walk off either end of the CharStream.
-    NextFailed,
-    /// A call to String::chars().next() failed. This is fatal as the internal
-    /// structure of the CharStream is now malformed.
     FallsOffEnd,
     /// CharStream's internal buffer was unwrapped to None. This is a
     /// programming error
@@ -28,21 +29,42 @@ pub enum CharStreamError {
 
 #[derive(Debug, PartialEq)]
 pub struct CharStream {
-    payload: RefCell<Vec<Option<char>>>,
-    value: String,
+    payload: Vec<char>,
     index: RefCell<usize>,
+    wrap: bool,
 }
 
 impl CharStream {
-    /// Constructs a new CharStream from a String.
+    /// Constructs a new CharStream from anything that can be borrowed or
+    /// owned as a `str` (e.g. a `String`, a `&str`, or a `Cow<str>`).
     ///
-    /// Allocate a vector with a len() 1 greater than the s.len(). This is so
-    /// that we can use index 0 as a sentinal value.
-    pub fn from(s: String) -> Self {
+    /// Callers that already own a `String` hand it over and avoid an extra
+    /// copy; callers with only a `&str` borrow it for the duration of the
+    /// call. The characters are collected once, up front, into the backing
+    /// `Vec<char>`.
+    pub fn from<'a, S: Into<Cow<'a, str>>>(s: S) -> Self {
         CharStream {
-            payload: RefCell::new(vec![None; s.len() + 1]),
-            value: s,
+            payload: s.into().chars().collect(),
             index: RefCell::new(0),
+            wrap: false,
+        }
+    }
+
+    /// Constructs a new, wrapping CharStream from anything that can be
+    /// borrowed or owned as a `str`. See `CharStream::from`.
+    ///
+    /// On a ring CharStream, calling `next()` at the final character resumes
+    /// at the first, and calling `prev()` at the first character resumes at
+    /// the last. Before the first `next()` there is no position to step back
+    /// from, so `prev()`, `peek_back_nth` and `retreat_by` still fail with
+    /// CharStreamError::FallsOffEnd there. This wrapping is a property of the
+    /// CharStream's own cursor movement and lookahead only: the owned
+    /// `IntoIter` adapter from `into_iter` still makes exactly one bounded
+    /// pass over the characters, so `collect`/`take_while`/etc. terminate.
+    pub fn ring<'a, S: Into<Cow<'a, str>>>(s: S) -> Self {
+        CharStream {
+            wrap: true,
+            ..CharStream::from(s)
         }
     }
 }
@@ -65,24 +87,21 @@ impl BiDirectionalIterator for CharStream {
     /// CharStreamError::ValueNotFound if indexing into a *good* index is None.
     /// This is a programming error.
     fn next(&self) -> Result<char, CharStreamError> {
-        let current = *self.index.borrow() + 1;
-        self.index.replace(current);
+        let mut current = *self.index.borrow() + 1;
 
-        if current > self.value.len() {
-            return Err(CharStreamError::FallsOffEnd);
-        }
-
-        // we've already been here. early return
-        if self.payload.borrow()[current].is_some() {
-            return self.payload.borrow()[current].ok_or(CharStreamError::ValueNotFound);
+        if current > self.payload.len() {
+            if self.wrap {
+                current = 1;
+            } else {
+                return Err(CharStreamError::FallsOffEnd);
+            }
         }
+        self.index.replace(current);
 
-        if let Some(c) = self.value.chars().next() {
-            self.payload.borrow_mut()[current] = Some(c);
-            self.payload.borrow()[current].ok_or(CharStreamError::ValueNotFound)
-        } else {
-            return Err(CharStreamError::FallsOffEnd);
-        }
+        self.payload
+            .get(current - 1)
+            .copied()
+            .ok_or(CharStreamError::ValueNotFound)
     }
 
     /// Retreat the CharStream by 1 returning the character
@@ -95,16 +114,25 @@ impl BiDirectionalIterator for CharStream {
     /// This is a programming error.
     fn prev(&self) -> Result<char, CharStreamError> {
         let current = *self.index.borrow();
-        if current == 1 {
-            return Err(CharStreamError::FallsOffEnd);
-        }
-
-        let current = *self.index.borrow() - 1;
+        let current = if current <= 1 {
+            // current == 0 means next() has never been called; there is no
+            // position to retreat from (wrapping "to the last character" is
+            // ambiguous here), so this falls off the end the same as current
+            // == 1 whether or not the stream wraps.
+            if self.wrap && current == 1 {
+                self.payload.len()
+            } else {
+                return Err(CharStreamError::FallsOffEnd);
+            }
+        } else {
+            current - 1
+        };
         self.index.replace(current);
 
-        let val = self.payload.borrow()[current];
-        assert!(current == 0 || self.payload.borrow()[current].is_some());
-        val.ok_or(CharStreamError::ValueNotFound)
+        self.payload
+            .get(current - 1)
+            .copied()
+            .ok_or(CharStreamError::ValueNotFound)
     }
 
     /// Advance the CharStream by 1 returning &self
@@ -115,26 +143,17 @@ impl BiDirectionalIterator for CharStream {
     ///
     /// CharStreamError::ValueNotFound if indexing into a *good* index is None.
     /// This is a programming error.
-    ///
-    /// CharStreamError::NextFailed if calling next on the internal String
-    /// fails. This error is fatal.
     fn peek_next(&self) -> Result<&CharStream, CharStreamError> {
-        let current = *self.index.borrow() + 1;
-        self.index.replace(current);
+        let mut current = *self.index.borrow() + 1;
 
-        if current > self.value.len() {
-            return Err(CharStreamError::FallsOffEnd);
-        }
-
-        // we've already been here. early return
-        if self.payload.borrow()[current].is_some() {
-            return Ok(self);
-        } else if let Some(c) = self.value.chars().next() {
-            self.payload.borrow_mut()[current] = Some(c);
-            assert!(self.payload.borrow()[current].is_some());
-        } else {
-            return Err(CharStreamError::NextFailed);
+        if current > self.payload.len() {
+            if self.wrap {
+                current = 1;
+            } else {
+                return Err(CharStreamError::FallsOffEnd);
+            }
         }
+        self.index.replace(current);
 
         Ok(self)
     }
@@ -147,17 +166,19 @@ impl BiDirectionalIterator for CharStream {
     ///
     /// CharStreamError::ValueNotFound if indexing into a *good* index is None.
     /// This is a programming error.
-    ///
-    /// CharStreamError::NextFailed if calling next on the internal String
-    /// fails. This error is fatal.
     fn peek_prev(&self) -> Result<&CharStream, CharStreamError> {
-        let current = *self.index.borrow() - 1;
-        if current == 0 {
-            return Err(CharStreamError::FallsOffEnd);
-        }
+        let current = *self.index.borrow();
+        let current = if current <= 1 {
+            if self.wrap {
+                self.payload.len()
+            } else {
+                return Err(CharStreamError::FallsOffEnd);
+            }
+        } else {
+            current - 1
+        };
         self.index.replace(current);
 
-        assert!(current == 0 || self.payload.borrow()[current].is_some());
         Ok(self)
     }
 
@@ -169,7 +190,245 @@ impl BiDirectionalIterator for CharStream {
     /// fails. This error is fatal.
     fn value(&self) -> Result<char, CharStreamError> {
         let current = *self.index.borrow();
-        self.payload.borrow()[current].ok_or(CharStreamError::ValueNotFound)
+        if current == 0 {
+            return Err(CharStreamError::ValueNotFound);
+        }
+
+        self.payload
+            .get(current - 1)
+            .copied()
+            .ok_or(CharStreamError::ValueNotFound)
+    }
+}
+
+impl IntoIterator for CharStream {
+    type Item = char;
+    type IntoIter = IntoIter;
+
+    /// Converts this CharStream into an owned adapter implementing the
+    /// standard library's `Iterator` and `DoubleEndedIterator` traits, so it
+    /// can be used with adapter combinators such as `map`, `take_while`,
+    /// `collect`, `rev`, and `for` loops.
+    fn into_iter(self) -> IntoIter {
+        let back = self.payload.len();
+        IntoIter {
+            stream: self,
+            front: 0,
+            back,
+        }
+    }
+}
+
+/// An owned `Iterator`/`DoubleEndedIterator` adapter over a CharStream's
+/// characters, yielded via `CharStream::into_iter` (or a `for` loop).
+///
+/// `front` and `back` track the 0-based bounds of the not-yet-yielded slice
+/// of `payload` directly, independent of the wrapped CharStream's own
+/// cursor. This is what lets interleaved `next()`/`next_back()` calls meet
+/// in the middle exactly once instead of re-yielding already-consumed
+/// characters. A side effect is that this adapter always makes exactly one
+/// pass over the stream's characters: a `CharStream::ring`'s wrapping only
+/// applies to the CharStream's own cursor movement and lookahead, not to this
+/// adapter, so `collect`/`take_while`/etc. terminate even on a ring stream
+/// instead of looping forever.
+pub struct IntoIter {
+    stream: CharStream,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for IntoIter {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let c = self.stream.payload[self.front];
+        self.front += 1;
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl CharStream {
+    /// Returns the character `n` positions ahead of the cursor without moving
+    /// it.
+    ///
+    /// Unlike `BiDirectionalIterator::peek_next`, this does not mutate the
+    /// cursor: since `payload` is an eagerly-populated `Vec<char>`, the
+    /// target position is looked up directly, in O(1), with no change to the
+    /// current index. On a `CharStream::ring`, a lookahead that would step
+    /// off the end instead wraps, the same way repeated `next()` calls
+    /// would.
+    ///
+    /// # Errors
+    /// CharStreamError::FallsOffEnd if the nth character would step off the
+    /// end of the CharStream and it is not a ring.
+    pub fn peek_nth(&self, n: usize) -> Result<char, CharStreamError> {
+        if n == 0 {
+            return BiDirectionalIterator::value(self);
+        }
+
+        let len = self.payload.len();
+        let current = *self.index.borrow();
+        let target = if n <= len - current {
+            current + n
+        } else if self.wrap && len > 0 {
+            let steps = (n - 1) % len;
+            (current + steps) % len + 1
+        } else {
+            return Err(CharStreamError::FallsOffEnd);
+        };
+
+        self.payload
+            .get(target - 1)
+            .copied()
+            .ok_or(CharStreamError::FallsOffEnd)
+    }
+
+    /// Returns the character `n` positions behind the cursor without moving
+    /// it. See `peek_nth` for the lookup behavior, including ring wrapping.
+    ///
+    /// # Errors
+    /// CharStreamError::FallsOffEnd if the nth character would step off the
+    /// front of the CharStream and it is not a ring, or, on a ring, if
+    /// `next()` has never been called.
+    pub fn peek_back_nth(&self, n: usize) -> Result<char, CharStreamError> {
+        if n == 0 {
+            return BiDirectionalIterator::value(self);
+        }
+
+        let current = *self.index.borrow();
+        let target = if n < current {
+            current - n
+        } else if self.wrap && current > 0 {
+            let len = self.payload.len();
+            let steps = n % len;
+            (current - 1 + len - steps) % len + 1
+        } else {
+            return Err(CharStreamError::FallsOffEnd);
+        };
+
+        self.payload
+            .get(target - 1)
+            .copied()
+            .ok_or(CharStreamError::FallsOffEnd)
+    }
+}
+
+impl CharStream {
+    /// Moves the cursor directly to the character at the 0-based
+    /// `char_index` and returns the character now under the cursor.
+    ///
+    /// On a `CharStream::ring`, an out-of-bounds `char_index` wraps modulo
+    /// the character count instead of failing, consistent with how `next()`
+    /// and `prev()` wrap on a ring stream.
+    ///
+    /// # Errors
+    /// CharStreamError::FallsOffEnd if `char_index` is out of bounds for this
+    /// CharStream and it is not a ring.
+    pub fn seek_to(&self, char_index: usize) -> Result<char, CharStreamError> {
+        let len = self.payload.len();
+        let char_index = if self.wrap && len > 0 {
+            char_index % len
+        } else {
+            char_index
+        };
+
+        let c = *self
+            .payload
+            .get(char_index)
+            .ok_or(CharStreamError::FallsOffEnd)?;
+        self.index.replace(char_index + 1);
+        Ok(c)
+    }
+
+    /// Moves the cursor forward by `n` characters with a single bounds
+    /// check, returning the character now under the cursor.
+    ///
+    /// If the move would cross the end of the CharStream the cursor is left
+    /// unchanged, so a failed move cannot leave it partway through. On a
+    /// `CharStream::ring` a move that would cross the end instead wraps, the
+    /// same way repeatedly calling `next()` would.
+    ///
+    /// # Errors
+    /// CharStreamError::FallsOffEnd if advancing by `n` would step off the
+    /// end of the CharStream and it is not a ring.
+    pub fn advance_by(&self, n: usize) -> Result<char, CharStreamError> {
+        if n == 0 {
+            return BiDirectionalIterator::value(self);
+        }
+
+        let len = self.payload.len();
+        let current = *self.index.borrow();
+        let target = if n <= len - current {
+            current + n
+        } else if self.wrap && len > 0 {
+            let steps = (n - 1) % len;
+            (current + steps) % len + 1
+        } else {
+            return Err(CharStreamError::FallsOffEnd);
+        };
+
+        let c = *self
+            .payload
+            .get(target - 1)
+            .ok_or(CharStreamError::FallsOffEnd)?;
+        self.index.replace(target);
+        Ok(c)
+    }
+
+    /// Moves the cursor backward by `n` characters with a single bounds
+    /// check, returning the character now under the cursor.
+    ///
+    /// If the move would cross the front of the CharStream the cursor is
+    /// left unchanged, so a failed move cannot leave it partway through. On
+    /// a `CharStream::ring` a move that would cross the front instead wraps,
+    /// the same way repeatedly calling `prev()` would.
+    ///
+    /// # Errors
+    /// CharStreamError::FallsOffEnd if retreating by `n` would step off the
+    /// front of the CharStream and it is not a ring, or, on a ring, if
+    /// `next()` has never been called.
+    pub fn retreat_by(&self, n: usize) -> Result<char, CharStreamError> {
+        if n == 0 {
+            return BiDirectionalIterator::value(self);
+        }
+
+        let current = *self.index.borrow();
+        let target = if n < current {
+            current - n
+        } else if self.wrap && current > 0 {
+            let len = self.payload.len();
+            let steps = n % len;
+            (current - 1 + len - steps) % len + 1
+        } else {
+            return Err(CharStreamError::FallsOffEnd);
+        };
+
+        let c = *self
+            .payload
+            .get(target - 1)
+            .ok_or(CharStreamError::FallsOffEnd)?;
+        self.index.replace(target);
+        Ok(c)
+    }
+}
+
+impl DoubleEndedIterator for IntoIter {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.stream.payload[self.back])
     }
 }
 
@@ -201,6 +460,20 @@ mod tests {
         assert_eq!(Err(CharStreamError::FallsOffEnd), stream.prev());
     }
 
+    #[test]
+    fn it_wont_panic_calling_prev_before_any_next() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.prev());
+    }
+
+    #[test]
+    fn it_wont_panic_calling_prev_before_any_next_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.prev());
+    }
+
     #[test]
     fn it_wont_step_off_the_end() {
         let value = String::from("foobar");
@@ -276,6 +549,50 @@ mod tests {
         assert_eq!(Ok('f'), stream.prev());
     }
 
+    #[test]
+    fn it_wraps_next_in_ring_mode() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        stream.next(); // 'f'
+        stream.next(); // 'o'
+        stream.next(); // 'o'
+        stream.next(); // 'b'
+        stream.next(); // 'a'
+        stream.next(); // 'r'
+        assert_eq!(Ok('f'), stream.next());
+    }
+
+    #[test]
+    fn it_wraps_prev_in_ring_mode() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        stream.next(); // 'f'
+        stream.next(); // 'o'
+        stream.next(); // 'o'
+        stream.next(); // 'b'
+        stream.next(); // 'a'
+        stream.next(); // 'r'
+        stream.prev(); // 'a'
+        stream.prev(); // 'b'
+        stream.prev(); // 'o'
+        stream.prev(); // 'o'
+        stream.prev(); // 'f'
+        assert_eq!(Ok('r'), stream.prev());
+    }
+
+    #[test]
+    fn it_does_not_wrap_by_default() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        stream.next(); // 'o'
+        stream.next(); // 'o'
+        stream.next(); // 'b'
+        stream.next(); // 'a'
+        stream.next(); // 'r'
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.next());
+    }
+
     #[test]
     fn it_can_get_back_to_where_it_started_peek() {
         let value = String::from("foobar");
@@ -292,4 +609,231 @@ mod tests {
         stream.prev(); // 'o'
         assert_eq!(Ok('f'), stream.prev());
     }
+
+    #[test]
+    fn it_supports_std_iterator() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        let collected: String = stream.into_iter().collect();
+        assert_eq!("foobar", collected);
+    }
+
+    #[test]
+    fn it_supports_double_ended_iterator() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        let collected: String = stream.into_iter().rev().collect();
+        assert_eq!("raboof", collected);
+    }
+
+    #[test]
+    fn it_supports_a_for_loop_via_intoiterator() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        let mut collected = String::new();
+        for c in stream {
+            collected.push(c);
+        }
+        assert_eq!("foobar", collected);
+    }
+
+    #[test]
+    fn it_meets_in_the_middle_when_interleaving_next_and_next_back() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        let mut iter = stream.into_iter();
+        assert_eq!(Some('f'), iter.next());
+        assert_eq!(Some('r'), iter.next_back());
+        assert_eq!(Some('a'), iter.next_back());
+        assert_eq!(Some('o'), iter.next());
+        assert_eq!(Some('o'), iter.next());
+        assert_eq!(Some('b'), iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn it_bounds_iteration_to_one_pass_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        let collected: String = stream.into_iter().take(20).collect();
+        assert_eq!("foobar", collected);
+    }
+
+    #[test]
+    fn it_can_peek_nth_without_moving_the_cursor() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('b'), stream.peek_nth(3));
+        assert_eq!(Ok('f'), stream.value());
+    }
+
+    #[test]
+    fn it_can_peek_nth_repeatedly() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('b'), stream.peek_nth(3));
+        assert_eq!(Ok('b'), stream.peek_nth(3));
+        assert_eq!(Ok('f'), stream.value());
+    }
+
+    #[test]
+    fn it_wont_peek_nth_off_the_end() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.peek_nth(10));
+    }
+
+    #[test]
+    fn it_wont_overflow_peeking_nth_with_a_huge_n() {
+        let stream = CharStream::from("foobar");
+        stream.next(); // 'f'
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.peek_nth(usize::MAX));
+
+        let ring = CharStream::ring("foobar");
+        ring.next(); // 'f'
+        assert_eq!(Ok('b'), ring.peek_nth(usize::MAX));
+    }
+
+    #[test]
+    fn it_can_peek_back_nth_without_moving_the_cursor() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        stream.next(); // 'o'
+        stream.next(); // 'o'
+        stream.next(); // 'b'
+        stream.next(); // 'a'
+        stream.next(); // 'r'
+        assert_eq!(Ok('o'), stream.peek_back_nth(4));
+        assert_eq!(Ok('r'), stream.value());
+    }
+
+    #[test]
+    fn it_wraps_peek_nth_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('f'), stream.peek_nth(6));
+        assert_eq!(Ok('f'), stream.value());
+    }
+
+    #[test]
+    fn it_wraps_peek_back_nth_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('r'), stream.peek_back_nth(1));
+        assert_eq!(Ok('f'), stream.value());
+    }
+
+    #[test]
+    fn it_can_be_constructed_from_a_borrowed_str() {
+        let stream = CharStream::from("foobar");
+        assert_eq!(Ok('f'), stream.next());
+    }
+
+    #[test]
+    fn it_correctly_indexes_multibyte_characters() {
+        let stream = CharStream::from("a\u{00e9}b");
+        stream.next(); // 'a'
+        assert_eq!(Ok('\u{00e9}'), stream.next());
+        assert_eq!(Ok('b'), stream.next());
+    }
+
+    #[test]
+    fn it_can_seek_to_an_index() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        assert_eq!(Ok('b'), stream.seek_to(3));
+    }
+
+    #[test]
+    fn it_wont_seek_out_of_bounds() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.seek_to(6));
+    }
+
+    #[test]
+    fn it_can_advance_by_n() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('o'), stream.advance_by(2));
+    }
+
+    #[test]
+    fn it_leaves_the_cursor_unchanged_on_a_failed_advance() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.advance_by(10));
+        assert_eq!(Ok('f'), stream.value());
+    }
+
+    #[test]
+    fn it_wont_overflow_advancing_by_a_huge_n() {
+        let stream = CharStream::from("foobar");
+        stream.next(); // 'f'
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.advance_by(usize::MAX));
+
+        let ring = CharStream::ring("foobar");
+        ring.next(); // 'f'
+        assert_eq!(Ok('b'), ring.advance_by(usize::MAX));
+    }
+
+    #[test]
+    fn it_can_retreat_by_n() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        stream.next(); // 'o'
+        stream.next(); // 'o'
+        stream.next(); // 'b'
+        assert_eq!(Ok('f'), stream.retreat_by(3));
+    }
+
+    #[test]
+    fn it_leaves_the_cursor_unchanged_on_a_failed_retreat() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        stream.next(); // 'f'
+        assert_eq!(Err(CharStreamError::FallsOffEnd), stream.retreat_by(5));
+        assert_eq!(Ok('f'), stream.value());
+    }
+
+    #[test]
+    fn it_wraps_seek_to_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        assert_eq!(Ok('f'), stream.seek_to(6));
+    }
+
+    #[test]
+    fn it_wraps_advance_by_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('f'), stream.advance_by(6));
+    }
+
+    #[test]
+    fn it_wraps_retreat_by_on_a_ring_stream() {
+        let value = String::from("foobar");
+        let stream = CharStream::ring(value);
+        stream.next(); // 'f'
+        assert_eq!(Ok('r'), stream.retreat_by(1));
+    }
+
+    #[test]
+    fn it_supports_iterator_adapters() {
+        let value = String::from("foobar");
+        let stream = CharStream::from(value);
+        let vowels: String = stream.into_iter().filter(|c| "aeiou".contains(*c)).collect();
+        assert_eq!("ooa", vowels);
+    }
 }